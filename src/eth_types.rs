@@ -0,0 +1,99 @@
+//! Ethereum types and conversions used by the zk_evm circuits.
+pub mod opcode_ids;
+pub use opcode_ids::OpcodeId;
+
+use bigint::{U256, U512};
+
+/// An EVM word.
+pub type Word = U256;
+
+/// Conversion of a wider integer back into a [`Word`].
+pub trait ToWordChecked {
+    /// Returns the low 256 bits, and whether any of the truncated high bits
+    /// were set.
+    fn to_word_checked(&self) -> (Word, bool);
+}
+
+impl ToWordChecked for U512 {
+    fn to_word_checked(&self) -> (Word, bool) {
+        let U512(ref limbs) = *self;
+        let truncated = limbs[4..].iter().any(|limb| *limb != 0);
+        (U256([limbs[0], limbs[1], limbs[2], limbs[3]]), truncated)
+    }
+}
+
+/// Decomposes a word into `N` little-endian limbs of `BITS` bits each.
+///
+/// Panics unless `N * BITS == 256` and each limb fits in a `u64`.
+pub fn to_limbs<const N: usize, const BITS: u32>(word: &Word) -> [u64; N] {
+    assert!(BITS <= 64, "limbs of {} bits do not fit in a u64", BITS);
+    assert_eq!(
+        N * BITS as usize,
+        256,
+        "{} limbs of {} bits do not cover a 256-bit word",
+        N,
+        BITS
+    );
+
+    let mask = (Word::one() << BITS as usize) - Word::one();
+    let mut limbs = [0u64; N];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        *limb = ((*word >> (i * BITS as usize)) & mask).low_u64();
+    }
+    limbs
+}
+
+/// Decomposes a word into sixteen little-endian 16-bit limbs.
+pub fn to_u16_limbs(word: &Word) -> [u16; 16] {
+    let mut limbs = [0u16; 16];
+    for (limb, value) in limbs.iter_mut().zip(to_limbs::<16, 16>(word).iter()) {
+        *limb = *value as u16;
+    }
+    limbs
+}
+
+/// Decomposes a word into eight little-endian 32-bit limbs.
+pub fn to_u32_limbs(word: &Word) -> [u32; 8] {
+    let mut limbs = [0u32; 8];
+    for (limb, value) in limbs.iter_mut().zip(to_limbs::<8, 32>(word).iter()) {
+        *limb = *value as u32;
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_limbs, to_u16_limbs, to_u32_limbs, ToWordChecked, Word};
+    use bigint::U512;
+
+    #[test]
+    fn limbs() {
+        assert_eq!(to_u16_limbs(&Word::MAX), [0xffff; 16]);
+        assert_eq!(to_u32_limbs(&Word::MAX), [0xffff_ffff; 8]);
+
+        let word = (Word::from(0x1234) << 240) + Word::from(0xabcd_0001u64);
+        let limbs = to_u16_limbs(&word);
+        assert_eq!(limbs[0], 0x0001);
+        assert_eq!(limbs[1], 0xabcd);
+        assert_eq!(limbs[15], 0x1234);
+        assert!(limbs[2..15].iter().all(|limb| *limb == 0));
+
+        assert_eq!(to_limbs::<4, 64>(&word), word.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn limbs_must_cover_word() {
+        to_limbs::<4, 32>(&Word::MAX);
+    }
+
+    #[test]
+    fn word_checked() {
+        assert_eq!(
+            (U512::from(1) << 256).to_word_checked(),
+            (Word::zero(), true)
+        );
+        assert_eq!(U512::from(Word::MAX).to_word_checked(), (Word::MAX, false));
+        assert_eq!(U512::MAX.to_word_checked(), (Word::MAX, true));
+    }
+}
@@ -0,0 +1,883 @@
+//! EVM opcodes and their static metadata.
+
+/// Maximum number of items on the EVM stack.
+pub const STACK_LIMIT: usize = 1024;
+
+/// An EVM opcode, covering all 256 byte values.
+///
+/// Bytes that don't define an opcode in the London fork, including the
+/// designated `INVALID` (0xfe), map to [`OpcodeId::Invalid`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OpcodeId {
+    /// `STOP`
+    STOP,
+    /// `ADD`
+    ADD,
+    /// `MUL`
+    MUL,
+    /// `SUB`
+    SUB,
+    /// `DIV`
+    DIV,
+    /// `SDIV`
+    SDIV,
+    /// `MOD`
+    MOD,
+    /// `SMOD`
+    SMOD,
+    /// `ADDMOD`
+    ADDMOD,
+    /// `MULMOD`
+    MULMOD,
+    /// `EXP`
+    EXP,
+    /// `SIGNEXTEND`
+    SIGNEXTEND,
+    /// `LT`
+    LT,
+    /// `GT`
+    GT,
+    /// `SLT`
+    SLT,
+    /// `SGT`
+    SGT,
+    /// `EQ`
+    EQ,
+    /// `ISZERO`
+    ISZERO,
+    /// `AND`
+    AND,
+    /// `OR`
+    OR,
+    /// `XOR`
+    XOR,
+    /// `NOT`
+    NOT,
+    /// `BYTE`
+    BYTE,
+    /// `SHL`
+    SHL,
+    /// `SHR`
+    SHR,
+    /// `SAR`
+    SAR,
+    /// `SHA3`
+    SHA3,
+    /// `ADDRESS`
+    ADDRESS,
+    /// `BALANCE`
+    BALANCE,
+    /// `ORIGIN`
+    ORIGIN,
+    /// `CALLER`
+    CALLER,
+    /// `CALLVALUE`
+    CALLVALUE,
+    /// `CALLDATALOAD`
+    CALLDATALOAD,
+    /// `CALLDATASIZE`
+    CALLDATASIZE,
+    /// `CALLDATACOPY`
+    CALLDATACOPY,
+    /// `CODESIZE`
+    CODESIZE,
+    /// `CODECOPY`
+    CODECOPY,
+    /// `GASPRICE`
+    GASPRICE,
+    /// `EXTCODESIZE`
+    EXTCODESIZE,
+    /// `EXTCODECOPY`
+    EXTCODECOPY,
+    /// `RETURNDATASIZE`
+    RETURNDATASIZE,
+    /// `RETURNDATACOPY`
+    RETURNDATACOPY,
+    /// `EXTCODEHASH`
+    EXTCODEHASH,
+    /// `BLOCKHASH`
+    BLOCKHASH,
+    /// `COINBASE`
+    COINBASE,
+    /// `TIMESTAMP`
+    TIMESTAMP,
+    /// `NUMBER`
+    NUMBER,
+    /// `DIFFICULTY`
+    DIFFICULTY,
+    /// `GASLIMIT`
+    GASLIMIT,
+    /// `CHAINID`
+    CHAINID,
+    /// `SELFBALANCE`
+    SELFBALANCE,
+    /// `BASEFEE`
+    BASEFEE,
+    /// `POP`
+    POP,
+    /// `MLOAD`
+    MLOAD,
+    /// `MSTORE`
+    MSTORE,
+    /// `MSTORE8`
+    MSTORE8,
+    /// `SLOAD`
+    SLOAD,
+    /// `SSTORE`
+    SSTORE,
+    /// `JUMP`
+    JUMP,
+    /// `JUMPI`
+    JUMPI,
+    /// `PC`
+    PC,
+    /// `MSIZE`
+    MSIZE,
+    /// `GAS`
+    GAS,
+    /// `JUMPDEST`
+    JUMPDEST,
+    /// `PUSH1`
+    PUSH1,
+    /// `PUSH2`
+    PUSH2,
+    /// `PUSH3`
+    PUSH3,
+    /// `PUSH4`
+    PUSH4,
+    /// `PUSH5`
+    PUSH5,
+    /// `PUSH6`
+    PUSH6,
+    /// `PUSH7`
+    PUSH7,
+    /// `PUSH8`
+    PUSH8,
+    /// `PUSH9`
+    PUSH9,
+    /// `PUSH10`
+    PUSH10,
+    /// `PUSH11`
+    PUSH11,
+    /// `PUSH12`
+    PUSH12,
+    /// `PUSH13`
+    PUSH13,
+    /// `PUSH14`
+    PUSH14,
+    /// `PUSH15`
+    PUSH15,
+    /// `PUSH16`
+    PUSH16,
+    /// `PUSH17`
+    PUSH17,
+    /// `PUSH18`
+    PUSH18,
+    /// `PUSH19`
+    PUSH19,
+    /// `PUSH20`
+    PUSH20,
+    /// `PUSH21`
+    PUSH21,
+    /// `PUSH22`
+    PUSH22,
+    /// `PUSH23`
+    PUSH23,
+    /// `PUSH24`
+    PUSH24,
+    /// `PUSH25`
+    PUSH25,
+    /// `PUSH26`
+    PUSH26,
+    /// `PUSH27`
+    PUSH27,
+    /// `PUSH28`
+    PUSH28,
+    /// `PUSH29`
+    PUSH29,
+    /// `PUSH30`
+    PUSH30,
+    /// `PUSH31`
+    PUSH31,
+    /// `PUSH32`
+    PUSH32,
+    /// `DUP1`
+    DUP1,
+    /// `DUP2`
+    DUP2,
+    /// `DUP3`
+    DUP3,
+    /// `DUP4`
+    DUP4,
+    /// `DUP5`
+    DUP5,
+    /// `DUP6`
+    DUP6,
+    /// `DUP7`
+    DUP7,
+    /// `DUP8`
+    DUP8,
+    /// `DUP9`
+    DUP9,
+    /// `DUP10`
+    DUP10,
+    /// `DUP11`
+    DUP11,
+    /// `DUP12`
+    DUP12,
+    /// `DUP13`
+    DUP13,
+    /// `DUP14`
+    DUP14,
+    /// `DUP15`
+    DUP15,
+    /// `DUP16`
+    DUP16,
+    /// `SWAP1`
+    SWAP1,
+    /// `SWAP2`
+    SWAP2,
+    /// `SWAP3`
+    SWAP3,
+    /// `SWAP4`
+    SWAP4,
+    /// `SWAP5`
+    SWAP5,
+    /// `SWAP6`
+    SWAP6,
+    /// `SWAP7`
+    SWAP7,
+    /// `SWAP8`
+    SWAP8,
+    /// `SWAP9`
+    SWAP9,
+    /// `SWAP10`
+    SWAP10,
+    /// `SWAP11`
+    SWAP11,
+    /// `SWAP12`
+    SWAP12,
+    /// `SWAP13`
+    SWAP13,
+    /// `SWAP14`
+    SWAP14,
+    /// `SWAP15`
+    SWAP15,
+    /// `SWAP16`
+    SWAP16,
+    /// `LOG0`
+    LOG0,
+    /// `LOG1`
+    LOG1,
+    /// `LOG2`
+    LOG2,
+    /// `LOG3`
+    LOG3,
+    /// `LOG4`
+    LOG4,
+    /// `CREATE`
+    CREATE,
+    /// `CALL`
+    CALL,
+    /// `CALLCODE`
+    CALLCODE,
+    /// `RETURN`
+    RETURN,
+    /// `DELEGATECALL`
+    DELEGATECALL,
+    /// `CREATE2`
+    CREATE2,
+    /// `STATICCALL`
+    STATICCALL,
+    /// `REVERT`
+    REVERT,
+    /// `SELFDESTRUCT`
+    SELFDESTRUCT,
+    /// An undefined opcode byte.
+    Invalid(u8),
+}
+
+impl OpcodeId {
+    /// Returns the byte encoding of the opcode.
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            OpcodeId::STOP => 0x00,
+            OpcodeId::ADD => 0x01,
+            OpcodeId::MUL => 0x02,
+            OpcodeId::SUB => 0x03,
+            OpcodeId::DIV => 0x04,
+            OpcodeId::SDIV => 0x05,
+            OpcodeId::MOD => 0x06,
+            OpcodeId::SMOD => 0x07,
+            OpcodeId::ADDMOD => 0x08,
+            OpcodeId::MULMOD => 0x09,
+            OpcodeId::EXP => 0x0a,
+            OpcodeId::SIGNEXTEND => 0x0b,
+            OpcodeId::LT => 0x10,
+            OpcodeId::GT => 0x11,
+            OpcodeId::SLT => 0x12,
+            OpcodeId::SGT => 0x13,
+            OpcodeId::EQ => 0x14,
+            OpcodeId::ISZERO => 0x15,
+            OpcodeId::AND => 0x16,
+            OpcodeId::OR => 0x17,
+            OpcodeId::XOR => 0x18,
+            OpcodeId::NOT => 0x19,
+            OpcodeId::BYTE => 0x1a,
+            OpcodeId::SHL => 0x1b,
+            OpcodeId::SHR => 0x1c,
+            OpcodeId::SAR => 0x1d,
+            OpcodeId::SHA3 => 0x20,
+            OpcodeId::ADDRESS => 0x30,
+            OpcodeId::BALANCE => 0x31,
+            OpcodeId::ORIGIN => 0x32,
+            OpcodeId::CALLER => 0x33,
+            OpcodeId::CALLVALUE => 0x34,
+            OpcodeId::CALLDATALOAD => 0x35,
+            OpcodeId::CALLDATASIZE => 0x36,
+            OpcodeId::CALLDATACOPY => 0x37,
+            OpcodeId::CODESIZE => 0x38,
+            OpcodeId::CODECOPY => 0x39,
+            OpcodeId::GASPRICE => 0x3a,
+            OpcodeId::EXTCODESIZE => 0x3b,
+            OpcodeId::EXTCODECOPY => 0x3c,
+            OpcodeId::RETURNDATASIZE => 0x3d,
+            OpcodeId::RETURNDATACOPY => 0x3e,
+            OpcodeId::EXTCODEHASH => 0x3f,
+            OpcodeId::BLOCKHASH => 0x40,
+            OpcodeId::COINBASE => 0x41,
+            OpcodeId::TIMESTAMP => 0x42,
+            OpcodeId::NUMBER => 0x43,
+            OpcodeId::DIFFICULTY => 0x44,
+            OpcodeId::GASLIMIT => 0x45,
+            OpcodeId::CHAINID => 0x46,
+            OpcodeId::SELFBALANCE => 0x47,
+            OpcodeId::BASEFEE => 0x48,
+            OpcodeId::POP => 0x50,
+            OpcodeId::MLOAD => 0x51,
+            OpcodeId::MSTORE => 0x52,
+            OpcodeId::MSTORE8 => 0x53,
+            OpcodeId::SLOAD => 0x54,
+            OpcodeId::SSTORE => 0x55,
+            OpcodeId::JUMP => 0x56,
+            OpcodeId::JUMPI => 0x57,
+            OpcodeId::PC => 0x58,
+            OpcodeId::MSIZE => 0x59,
+            OpcodeId::GAS => 0x5a,
+            OpcodeId::JUMPDEST => 0x5b,
+            OpcodeId::PUSH1 => 0x60,
+            OpcodeId::PUSH2 => 0x61,
+            OpcodeId::PUSH3 => 0x62,
+            OpcodeId::PUSH4 => 0x63,
+            OpcodeId::PUSH5 => 0x64,
+            OpcodeId::PUSH6 => 0x65,
+            OpcodeId::PUSH7 => 0x66,
+            OpcodeId::PUSH8 => 0x67,
+            OpcodeId::PUSH9 => 0x68,
+            OpcodeId::PUSH10 => 0x69,
+            OpcodeId::PUSH11 => 0x6a,
+            OpcodeId::PUSH12 => 0x6b,
+            OpcodeId::PUSH13 => 0x6c,
+            OpcodeId::PUSH14 => 0x6d,
+            OpcodeId::PUSH15 => 0x6e,
+            OpcodeId::PUSH16 => 0x6f,
+            OpcodeId::PUSH17 => 0x70,
+            OpcodeId::PUSH18 => 0x71,
+            OpcodeId::PUSH19 => 0x72,
+            OpcodeId::PUSH20 => 0x73,
+            OpcodeId::PUSH21 => 0x74,
+            OpcodeId::PUSH22 => 0x75,
+            OpcodeId::PUSH23 => 0x76,
+            OpcodeId::PUSH24 => 0x77,
+            OpcodeId::PUSH25 => 0x78,
+            OpcodeId::PUSH26 => 0x79,
+            OpcodeId::PUSH27 => 0x7a,
+            OpcodeId::PUSH28 => 0x7b,
+            OpcodeId::PUSH29 => 0x7c,
+            OpcodeId::PUSH30 => 0x7d,
+            OpcodeId::PUSH31 => 0x7e,
+            OpcodeId::PUSH32 => 0x7f,
+            OpcodeId::DUP1 => 0x80,
+            OpcodeId::DUP2 => 0x81,
+            OpcodeId::DUP3 => 0x82,
+            OpcodeId::DUP4 => 0x83,
+            OpcodeId::DUP5 => 0x84,
+            OpcodeId::DUP6 => 0x85,
+            OpcodeId::DUP7 => 0x86,
+            OpcodeId::DUP8 => 0x87,
+            OpcodeId::DUP9 => 0x88,
+            OpcodeId::DUP10 => 0x89,
+            OpcodeId::DUP11 => 0x8a,
+            OpcodeId::DUP12 => 0x8b,
+            OpcodeId::DUP13 => 0x8c,
+            OpcodeId::DUP14 => 0x8d,
+            OpcodeId::DUP15 => 0x8e,
+            OpcodeId::DUP16 => 0x8f,
+            OpcodeId::SWAP1 => 0x90,
+            OpcodeId::SWAP2 => 0x91,
+            OpcodeId::SWAP3 => 0x92,
+            OpcodeId::SWAP4 => 0x93,
+            OpcodeId::SWAP5 => 0x94,
+            OpcodeId::SWAP6 => 0x95,
+            OpcodeId::SWAP7 => 0x96,
+            OpcodeId::SWAP8 => 0x97,
+            OpcodeId::SWAP9 => 0x98,
+            OpcodeId::SWAP10 => 0x99,
+            OpcodeId::SWAP11 => 0x9a,
+            OpcodeId::SWAP12 => 0x9b,
+            OpcodeId::SWAP13 => 0x9c,
+            OpcodeId::SWAP14 => 0x9d,
+            OpcodeId::SWAP15 => 0x9e,
+            OpcodeId::SWAP16 => 0x9f,
+            OpcodeId::LOG0 => 0xa0,
+            OpcodeId::LOG1 => 0xa1,
+            OpcodeId::LOG2 => 0xa2,
+            OpcodeId::LOG3 => 0xa3,
+            OpcodeId::LOG4 => 0xa4,
+            OpcodeId::CREATE => 0xf0,
+            OpcodeId::CALL => 0xf1,
+            OpcodeId::CALLCODE => 0xf2,
+            OpcodeId::RETURN => 0xf3,
+            OpcodeId::DELEGATECALL => 0xf4,
+            OpcodeId::CREATE2 => 0xf5,
+            OpcodeId::STATICCALL => 0xfa,
+            OpcodeId::REVERT => 0xfd,
+            OpcodeId::SELFDESTRUCT => 0xff,
+            OpcodeId::Invalid(byte) => *byte,
+        }
+    }
+
+    /// Iterates over the opcodes of all 256 byte values, in byte order.
+    pub fn iter() -> impl Iterator<Item = OpcodeId> {
+        (0..=u8::MAX).map(OpcodeId::from)
+    }
+
+    /// Returns the constant part of the gas cost, per the Yellow Paper
+    /// (London).
+    ///
+    /// Opcodes with dynamic costs only report their static base: for the
+    /// access-list opcodes this is the warm access cost, with the cold
+    /// surcharge left dynamic, and `SSTORE`, `RETURN` and `REVERT` are
+    /// entirely dynamic.
+    pub fn constant_gas_cost(&self) -> u64 {
+        self.info().0
+    }
+
+    /// Returns the minimum stack length needed to execute the opcode.
+    pub fn min_stack(&self) -> usize {
+        self.info().1
+    }
+
+    /// Returns the maximum stack length from which the opcode can execute
+    /// without overflowing [`STACK_LIMIT`].
+    pub fn max_stack(&self) -> usize {
+        let (_, pops, pushes) = self.info();
+        STACK_LIMIT + pops - pushes
+    }
+
+    /// Returns the number of immediate bytes following the opcode, which is
+    /// nonzero only for `PUSHn`.
+    pub fn push_size(&self) -> usize {
+        match self.as_u8() {
+            byte @ 0x60..=0x7f => (byte - 0x5f) as usize,
+            _ => 0,
+        }
+    }
+
+    /// Returns whether the opcode is one of the message call opcodes.
+    pub fn is_call(&self) -> bool {
+        matches!(
+            self,
+            OpcodeId::CALL | OpcodeId::CALLCODE | OpcodeId::DELEGATECALL | OpcodeId::STATICCALL
+        )
+    }
+
+    /// Returns whether the opcode creates a contract.
+    pub fn is_create(&self) -> bool {
+        matches!(self, OpcodeId::CREATE | OpcodeId::CREATE2)
+    }
+
+    /// Returns whether the opcode may execute in a static context.
+    ///
+    /// `CALL` is reported as valid, because it is only rejected when it
+    /// transfers a nonzero value, which is checked at execution time.
+    pub fn valid_in_static(&self) -> bool {
+        !matches!(
+            self,
+            OpcodeId::SSTORE
+                | OpcodeId::LOG0
+                | OpcodeId::LOG1
+                | OpcodeId::LOG2
+                | OpcodeId::LOG3
+                | OpcodeId::LOG4
+                | OpcodeId::CREATE
+                | OpcodeId::CREATE2
+                | OpcodeId::SELFDESTRUCT
+        )
+    }
+
+    /// Returns `(constant gas cost, stack items popped, stack items pushed)`.
+    fn info(&self) -> (u64, usize, usize) {
+        match self {
+            OpcodeId::STOP => (0, 0, 0),
+            OpcodeId::ADD => (3, 2, 1),
+            OpcodeId::MUL => (5, 2, 1),
+            OpcodeId::SUB => (3, 2, 1),
+            OpcodeId::DIV => (5, 2, 1),
+            OpcodeId::SDIV => (5, 2, 1),
+            OpcodeId::MOD => (5, 2, 1),
+            OpcodeId::SMOD => (5, 2, 1),
+            OpcodeId::ADDMOD => (8, 3, 1),
+            OpcodeId::MULMOD => (8, 3, 1),
+            OpcodeId::EXP => (10, 2, 1),
+            OpcodeId::SIGNEXTEND => (5, 2, 1),
+            OpcodeId::LT => (3, 2, 1),
+            OpcodeId::GT => (3, 2, 1),
+            OpcodeId::SLT => (3, 2, 1),
+            OpcodeId::SGT => (3, 2, 1),
+            OpcodeId::EQ => (3, 2, 1),
+            OpcodeId::ISZERO => (3, 1, 1),
+            OpcodeId::AND => (3, 2, 1),
+            OpcodeId::OR => (3, 2, 1),
+            OpcodeId::XOR => (3, 2, 1),
+            OpcodeId::NOT => (3, 1, 1),
+            OpcodeId::BYTE => (3, 2, 1),
+            OpcodeId::SHL => (3, 2, 1),
+            OpcodeId::SHR => (3, 2, 1),
+            OpcodeId::SAR => (3, 2, 1),
+            OpcodeId::SHA3 => (30, 2, 1),
+            OpcodeId::ADDRESS => (2, 0, 1),
+            OpcodeId::BALANCE => (100, 1, 1),
+            OpcodeId::ORIGIN => (2, 0, 1),
+            OpcodeId::CALLER => (2, 0, 1),
+            OpcodeId::CALLVALUE => (2, 0, 1),
+            OpcodeId::CALLDATALOAD => (3, 1, 1),
+            OpcodeId::CALLDATASIZE => (2, 0, 1),
+            OpcodeId::CALLDATACOPY => (3, 3, 0),
+            OpcodeId::CODESIZE => (2, 0, 1),
+            OpcodeId::CODECOPY => (3, 3, 0),
+            OpcodeId::GASPRICE => (2, 0, 1),
+            OpcodeId::EXTCODESIZE => (100, 1, 1),
+            OpcodeId::EXTCODECOPY => (100, 4, 0),
+            OpcodeId::RETURNDATASIZE => (2, 0, 1),
+            OpcodeId::RETURNDATACOPY => (3, 3, 0),
+            OpcodeId::EXTCODEHASH => (100, 1, 1),
+            OpcodeId::BLOCKHASH => (20, 1, 1),
+            OpcodeId::COINBASE => (2, 0, 1),
+            OpcodeId::TIMESTAMP => (2, 0, 1),
+            OpcodeId::NUMBER => (2, 0, 1),
+            OpcodeId::DIFFICULTY => (2, 0, 1),
+            OpcodeId::GASLIMIT => (2, 0, 1),
+            OpcodeId::CHAINID => (2, 0, 1),
+            OpcodeId::SELFBALANCE => (5, 0, 1),
+            OpcodeId::BASEFEE => (2, 0, 1),
+            OpcodeId::POP => (2, 1, 0),
+            OpcodeId::MLOAD => (3, 1, 1),
+            OpcodeId::MSTORE => (3, 2, 0),
+            OpcodeId::MSTORE8 => (3, 2, 0),
+            OpcodeId::SLOAD => (100, 1, 1),
+            OpcodeId::SSTORE => (0, 2, 0),
+            OpcodeId::JUMP => (8, 1, 0),
+            OpcodeId::JUMPI => (10, 2, 0),
+            OpcodeId::PC => (2, 0, 1),
+            OpcodeId::MSIZE => (2, 0, 1),
+            OpcodeId::GAS => (2, 0, 1),
+            OpcodeId::JUMPDEST => (1, 0, 0),
+            OpcodeId::PUSH1 => (3, 0, 1),
+            OpcodeId::PUSH2 => (3, 0, 1),
+            OpcodeId::PUSH3 => (3, 0, 1),
+            OpcodeId::PUSH4 => (3, 0, 1),
+            OpcodeId::PUSH5 => (3, 0, 1),
+            OpcodeId::PUSH6 => (3, 0, 1),
+            OpcodeId::PUSH7 => (3, 0, 1),
+            OpcodeId::PUSH8 => (3, 0, 1),
+            OpcodeId::PUSH9 => (3, 0, 1),
+            OpcodeId::PUSH10 => (3, 0, 1),
+            OpcodeId::PUSH11 => (3, 0, 1),
+            OpcodeId::PUSH12 => (3, 0, 1),
+            OpcodeId::PUSH13 => (3, 0, 1),
+            OpcodeId::PUSH14 => (3, 0, 1),
+            OpcodeId::PUSH15 => (3, 0, 1),
+            OpcodeId::PUSH16 => (3, 0, 1),
+            OpcodeId::PUSH17 => (3, 0, 1),
+            OpcodeId::PUSH18 => (3, 0, 1),
+            OpcodeId::PUSH19 => (3, 0, 1),
+            OpcodeId::PUSH20 => (3, 0, 1),
+            OpcodeId::PUSH21 => (3, 0, 1),
+            OpcodeId::PUSH22 => (3, 0, 1),
+            OpcodeId::PUSH23 => (3, 0, 1),
+            OpcodeId::PUSH24 => (3, 0, 1),
+            OpcodeId::PUSH25 => (3, 0, 1),
+            OpcodeId::PUSH26 => (3, 0, 1),
+            OpcodeId::PUSH27 => (3, 0, 1),
+            OpcodeId::PUSH28 => (3, 0, 1),
+            OpcodeId::PUSH29 => (3, 0, 1),
+            OpcodeId::PUSH30 => (3, 0, 1),
+            OpcodeId::PUSH31 => (3, 0, 1),
+            OpcodeId::PUSH32 => (3, 0, 1),
+            OpcodeId::DUP1 => (3, 1, 2),
+            OpcodeId::DUP2 => (3, 2, 3),
+            OpcodeId::DUP3 => (3, 3, 4),
+            OpcodeId::DUP4 => (3, 4, 5),
+            OpcodeId::DUP5 => (3, 5, 6),
+            OpcodeId::DUP6 => (3, 6, 7),
+            OpcodeId::DUP7 => (3, 7, 8),
+            OpcodeId::DUP8 => (3, 8, 9),
+            OpcodeId::DUP9 => (3, 9, 10),
+            OpcodeId::DUP10 => (3, 10, 11),
+            OpcodeId::DUP11 => (3, 11, 12),
+            OpcodeId::DUP12 => (3, 12, 13),
+            OpcodeId::DUP13 => (3, 13, 14),
+            OpcodeId::DUP14 => (3, 14, 15),
+            OpcodeId::DUP15 => (3, 15, 16),
+            OpcodeId::DUP16 => (3, 16, 17),
+            OpcodeId::SWAP1 => (3, 2, 2),
+            OpcodeId::SWAP2 => (3, 3, 3),
+            OpcodeId::SWAP3 => (3, 4, 4),
+            OpcodeId::SWAP4 => (3, 5, 5),
+            OpcodeId::SWAP5 => (3, 6, 6),
+            OpcodeId::SWAP6 => (3, 7, 7),
+            OpcodeId::SWAP7 => (3, 8, 8),
+            OpcodeId::SWAP8 => (3, 9, 9),
+            OpcodeId::SWAP9 => (3, 10, 10),
+            OpcodeId::SWAP10 => (3, 11, 11),
+            OpcodeId::SWAP11 => (3, 12, 12),
+            OpcodeId::SWAP12 => (3, 13, 13),
+            OpcodeId::SWAP13 => (3, 14, 14),
+            OpcodeId::SWAP14 => (3, 15, 15),
+            OpcodeId::SWAP15 => (3, 16, 16),
+            OpcodeId::SWAP16 => (3, 17, 17),
+            OpcodeId::LOG0 => (375, 2, 0),
+            OpcodeId::LOG1 => (375, 3, 0),
+            OpcodeId::LOG2 => (375, 4, 0),
+            OpcodeId::LOG3 => (375, 5, 0),
+            OpcodeId::LOG4 => (375, 6, 0),
+            OpcodeId::CREATE => (32000, 3, 1),
+            OpcodeId::CALL => (100, 7, 1),
+            OpcodeId::CALLCODE => (100, 7, 1),
+            OpcodeId::RETURN => (0, 2, 0),
+            OpcodeId::DELEGATECALL => (100, 6, 1),
+            OpcodeId::CREATE2 => (32000, 4, 1),
+            OpcodeId::STATICCALL => (100, 6, 1),
+            OpcodeId::REVERT => (0, 2, 0),
+            OpcodeId::SELFDESTRUCT => (5000, 1, 0),
+            OpcodeId::Invalid(_) => (0, 0, 0),
+        }
+    }
+}
+
+impl From<u8> for OpcodeId {
+    fn from(byte: u8) -> Self {
+        match byte {
+            0x00 => OpcodeId::STOP,
+            0x01 => OpcodeId::ADD,
+            0x02 => OpcodeId::MUL,
+            0x03 => OpcodeId::SUB,
+            0x04 => OpcodeId::DIV,
+            0x05 => OpcodeId::SDIV,
+            0x06 => OpcodeId::MOD,
+            0x07 => OpcodeId::SMOD,
+            0x08 => OpcodeId::ADDMOD,
+            0x09 => OpcodeId::MULMOD,
+            0x0a => OpcodeId::EXP,
+            0x0b => OpcodeId::SIGNEXTEND,
+            0x10 => OpcodeId::LT,
+            0x11 => OpcodeId::GT,
+            0x12 => OpcodeId::SLT,
+            0x13 => OpcodeId::SGT,
+            0x14 => OpcodeId::EQ,
+            0x15 => OpcodeId::ISZERO,
+            0x16 => OpcodeId::AND,
+            0x17 => OpcodeId::OR,
+            0x18 => OpcodeId::XOR,
+            0x19 => OpcodeId::NOT,
+            0x1a => OpcodeId::BYTE,
+            0x1b => OpcodeId::SHL,
+            0x1c => OpcodeId::SHR,
+            0x1d => OpcodeId::SAR,
+            0x20 => OpcodeId::SHA3,
+            0x30 => OpcodeId::ADDRESS,
+            0x31 => OpcodeId::BALANCE,
+            0x32 => OpcodeId::ORIGIN,
+            0x33 => OpcodeId::CALLER,
+            0x34 => OpcodeId::CALLVALUE,
+            0x35 => OpcodeId::CALLDATALOAD,
+            0x36 => OpcodeId::CALLDATASIZE,
+            0x37 => OpcodeId::CALLDATACOPY,
+            0x38 => OpcodeId::CODESIZE,
+            0x39 => OpcodeId::CODECOPY,
+            0x3a => OpcodeId::GASPRICE,
+            0x3b => OpcodeId::EXTCODESIZE,
+            0x3c => OpcodeId::EXTCODECOPY,
+            0x3d => OpcodeId::RETURNDATASIZE,
+            0x3e => OpcodeId::RETURNDATACOPY,
+            0x3f => OpcodeId::EXTCODEHASH,
+            0x40 => OpcodeId::BLOCKHASH,
+            0x41 => OpcodeId::COINBASE,
+            0x42 => OpcodeId::TIMESTAMP,
+            0x43 => OpcodeId::NUMBER,
+            0x44 => OpcodeId::DIFFICULTY,
+            0x45 => OpcodeId::GASLIMIT,
+            0x46 => OpcodeId::CHAINID,
+            0x47 => OpcodeId::SELFBALANCE,
+            0x48 => OpcodeId::BASEFEE,
+            0x50 => OpcodeId::POP,
+            0x51 => OpcodeId::MLOAD,
+            0x52 => OpcodeId::MSTORE,
+            0x53 => OpcodeId::MSTORE8,
+            0x54 => OpcodeId::SLOAD,
+            0x55 => OpcodeId::SSTORE,
+            0x56 => OpcodeId::JUMP,
+            0x57 => OpcodeId::JUMPI,
+            0x58 => OpcodeId::PC,
+            0x59 => OpcodeId::MSIZE,
+            0x5a => OpcodeId::GAS,
+            0x5b => OpcodeId::JUMPDEST,
+            0x60 => OpcodeId::PUSH1,
+            0x61 => OpcodeId::PUSH2,
+            0x62 => OpcodeId::PUSH3,
+            0x63 => OpcodeId::PUSH4,
+            0x64 => OpcodeId::PUSH5,
+            0x65 => OpcodeId::PUSH6,
+            0x66 => OpcodeId::PUSH7,
+            0x67 => OpcodeId::PUSH8,
+            0x68 => OpcodeId::PUSH9,
+            0x69 => OpcodeId::PUSH10,
+            0x6a => OpcodeId::PUSH11,
+            0x6b => OpcodeId::PUSH12,
+            0x6c => OpcodeId::PUSH13,
+            0x6d => OpcodeId::PUSH14,
+            0x6e => OpcodeId::PUSH15,
+            0x6f => OpcodeId::PUSH16,
+            0x70 => OpcodeId::PUSH17,
+            0x71 => OpcodeId::PUSH18,
+            0x72 => OpcodeId::PUSH19,
+            0x73 => OpcodeId::PUSH20,
+            0x74 => OpcodeId::PUSH21,
+            0x75 => OpcodeId::PUSH22,
+            0x76 => OpcodeId::PUSH23,
+            0x77 => OpcodeId::PUSH24,
+            0x78 => OpcodeId::PUSH25,
+            0x79 => OpcodeId::PUSH26,
+            0x7a => OpcodeId::PUSH27,
+            0x7b => OpcodeId::PUSH28,
+            0x7c => OpcodeId::PUSH29,
+            0x7d => OpcodeId::PUSH30,
+            0x7e => OpcodeId::PUSH31,
+            0x7f => OpcodeId::PUSH32,
+            0x80 => OpcodeId::DUP1,
+            0x81 => OpcodeId::DUP2,
+            0x82 => OpcodeId::DUP3,
+            0x83 => OpcodeId::DUP4,
+            0x84 => OpcodeId::DUP5,
+            0x85 => OpcodeId::DUP6,
+            0x86 => OpcodeId::DUP7,
+            0x87 => OpcodeId::DUP8,
+            0x88 => OpcodeId::DUP9,
+            0x89 => OpcodeId::DUP10,
+            0x8a => OpcodeId::DUP11,
+            0x8b => OpcodeId::DUP12,
+            0x8c => OpcodeId::DUP13,
+            0x8d => OpcodeId::DUP14,
+            0x8e => OpcodeId::DUP15,
+            0x8f => OpcodeId::DUP16,
+            0x90 => OpcodeId::SWAP1,
+            0x91 => OpcodeId::SWAP2,
+            0x92 => OpcodeId::SWAP3,
+            0x93 => OpcodeId::SWAP4,
+            0x94 => OpcodeId::SWAP5,
+            0x95 => OpcodeId::SWAP6,
+            0x96 => OpcodeId::SWAP7,
+            0x97 => OpcodeId::SWAP8,
+            0x98 => OpcodeId::SWAP9,
+            0x99 => OpcodeId::SWAP10,
+            0x9a => OpcodeId::SWAP11,
+            0x9b => OpcodeId::SWAP12,
+            0x9c => OpcodeId::SWAP13,
+            0x9d => OpcodeId::SWAP14,
+            0x9e => OpcodeId::SWAP15,
+            0x9f => OpcodeId::SWAP16,
+            0xa0 => OpcodeId::LOG0,
+            0xa1 => OpcodeId::LOG1,
+            0xa2 => OpcodeId::LOG2,
+            0xa3 => OpcodeId::LOG3,
+            0xa4 => OpcodeId::LOG4,
+            0xf0 => OpcodeId::CREATE,
+            0xf1 => OpcodeId::CALL,
+            0xf2 => OpcodeId::CALLCODE,
+            0xf3 => OpcodeId::RETURN,
+            0xf4 => OpcodeId::DELEGATECALL,
+            0xf5 => OpcodeId::CREATE2,
+            0xfa => OpcodeId::STATICCALL,
+            0xfd => OpcodeId::REVERT,
+            0xff => OpcodeId::SELFDESTRUCT,
+            byte => OpcodeId::Invalid(byte),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpcodeId, STACK_LIMIT};
+
+    #[test]
+    fn byte_round_trip() {
+        for (byte, opcode) in OpcodeId::iter().enumerate() {
+            assert_eq!(opcode.as_u8() as usize, byte);
+        }
+        assert_eq!(OpcodeId::from(0x0c), OpcodeId::Invalid(0x0c));
+        assert_eq!(OpcodeId::from(0xfe), OpcodeId::Invalid(0xfe));
+    }
+
+    #[test]
+    fn yellow_paper_metadata() {
+        // (opcode, G, min stack, items pushed)
+        for (opcode, gas, min_stack, pushes) in [
+            (OpcodeId::STOP, 0, 0, 0),
+            (OpcodeId::ADD, 3, 2, 1),
+            (OpcodeId::MUL, 5, 2, 1),
+            (OpcodeId::ADDMOD, 8, 3, 1),
+            (OpcodeId::EXP, 10, 2, 1),
+            (OpcodeId::SHA3, 30, 2, 1),
+            (OpcodeId::BLOCKHASH, 20, 1, 1),
+            (OpcodeId::JUMP, 8, 1, 0),
+            (OpcodeId::JUMPI, 10, 2, 0),
+            (OpcodeId::JUMPDEST, 1, 0, 0),
+            (OpcodeId::DUP16, 3, 16, 17),
+            (OpcodeId::SWAP16, 3, 17, 17),
+            (OpcodeId::LOG4, 375, 6, 0),
+            (OpcodeId::CREATE, 32000, 3, 1),
+            (OpcodeId::CALL, 100, 7, 1),
+            (OpcodeId::SELFDESTRUCT, 5000, 1, 0),
+        ]
+        .iter()
+        {
+            assert_eq!(opcode.constant_gas_cost(), *gas, "{:?}", opcode);
+            assert_eq!(opcode.min_stack(), *min_stack, "{:?}", opcode);
+            assert_eq!(
+                opcode.max_stack(),
+                STACK_LIMIT + min_stack - pushes,
+                "{:?}",
+                opcode
+            );
+        }
+
+        assert!(OpcodeId::DELEGATECALL.is_call());
+        assert!(!OpcodeId::CREATE.is_call());
+        assert!(OpcodeId::CREATE2.is_create());
+        assert!(!OpcodeId::SSTORE.valid_in_static());
+        assert!(OpcodeId::SLOAD.valid_in_static());
+    }
+
+    #[test]
+    fn push_size() {
+        for n in 1..=32u8 {
+            let opcode = OpcodeId::from(0x5f + n);
+            assert_eq!(format!("{:?}", opcode), format!("PUSH{}", n));
+            assert_eq!(opcode.push_size(), n as usize);
+        }
+        assert_eq!(OpcodeId::JUMPDEST.push_size(), 0);
+        assert_eq!(OpcodeId::DUP1.push_size(), 0);
+    }
+}
@@ -0,0 +1,66 @@
+//! Helpers on field elements used by the zk_evm circuits.
+use pasta_curves::arithmetic::FieldExt;
+use std::convert::TryInto;
+
+/// Extension methods for [`FieldExt`] elements.
+pub trait Field: FieldExt {
+    /// Returns the low and high 128 bits of the canonical representation.
+    fn to_u128_pair(&self) -> (u128, u128) {
+        let bytes = self.to_bytes();
+        (
+            u128::from_le_bytes(bytes[..16].try_into().unwrap()),
+            u128::from_le_bytes(bytes[16..].try_into().unwrap()),
+        )
+    }
+
+    /// Returns whether `self` is less than `other` as canonical integers,
+    /// comparing the representations from the most significant byte down.
+    /// This depends only on the canonical representation, so witness sorting
+    /// can rely on it regardless of how `Ord` is implemented.
+    fn canonical_lt(&self, other: &Self) -> bool {
+        self.to_bytes()
+            .iter()
+            .rev()
+            .lt(other.to_bytes().iter().rev())
+    }
+}
+
+impl<F: FieldExt> Field for F {}
+
+#[cfg(test)]
+mod tests {
+    use super::Field;
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    #[test]
+    fn u128_pair() {
+        let v = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        assert_eq!(pallas::Base::from_u128(v).get_lower_128(), v);
+        assert_eq!(pallas::Base::from_u128(v).to_u128_pair(), (v, 0));
+
+        let hi = pallas::Base::from_u128(1 << 127) * pallas::Base::from_u64(4);
+        assert_eq!(hi.to_u128_pair(), (0, 2));
+    }
+
+    #[test]
+    fn canonical_lt() {
+        let one = pallas::Base::one();
+        assert!(one.canonical_lt(&pallas::Base::from_u64(2)));
+        assert!(!one.canonical_lt(&one));
+
+        let samples = [0u128, 1, 255, 256, 1 << 64, u128::MAX];
+        for a in samples.iter() {
+            for b in samples.iter() {
+                assert_eq!(
+                    pallas::Base::from_u128(*a).canonical_lt(&pallas::Base::from_u128(*b)),
+                    a < b
+                );
+            }
+        }
+
+        // p - 1 is the largest canonical value.
+        let max = -one;
+        assert!(pallas::Base::from_u128(u128::MAX).canonical_lt(&max));
+        assert!(!max.canonical_lt(&pallas::Base::zero()));
+    }
+}
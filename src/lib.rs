@@ -9,9 +9,13 @@
 #![deny(missing_docs)]
 #![deny(unsafe_code)]
 
+pub mod eth_types;
 pub mod evm_circuit;
+pub mod field;
 pub mod gadget;
 pub mod state_circuit;
 
+#[cfg(test)]
+mod test_util;
 #[cfg(test)]
 mod test_vectors;
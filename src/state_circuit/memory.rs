@@ -85,6 +85,51 @@ pub(crate) struct MemoryOp<F: FieldExt> {
     steps: Vec<Option<ReadWrite<F>>>,
 }
 
+/// The first pair of operations at an address whose steps are not strictly
+/// increasing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct StateInconsistency<F: FieldExt> {
+    address: F,
+    prev_step: usize,
+    step: usize,
+}
+
+impl<F: FieldExt> MemoryOp<F> {
+    /// Checks that the steps at this address are strictly increasing,
+    /// starting after the `init` row at step 0. Unknown steps are skipped.
+    fn check_consistency(&self) -> Result<(), StateInconsistency<F>> {
+        let mut prev_step = Step(0);
+        for read_write in self.steps.iter().flatten() {
+            let step = read_write.step();
+            if step.0 <= prev_step.0 {
+                return Err(StateInconsistency {
+                    address: self.address.0,
+                    prev_step: prev_step.0,
+                    step: step.0,
+                });
+            }
+            prev_step = step;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sanity check to run over the witnessed memory operations before
+/// synthesis. Reports the first address whose steps are out of order.
+/// Step ordering is not constrained in-circuit yet (see the `step_prev <
+/// step_cur` TODO in `Config::configure`), so this check is currently the
+/// only thing that detects it.
+///
+/// Steps must be strictly greater than the `init` row's step 0, so an
+/// operation at `Step(0)` is reported. Unknown (`None`) steps are skipped
+/// rather than reported; [`Config::assign`] still rejects them.
+pub(crate) fn check_rw_consistency<F: FieldExt>(
+    ops: &[MemoryOp<F>],
+) -> Result<(), StateInconsistency<F>> {
+    ops.iter().try_for_each(|op| op.check_consistency())
+}
+
 /*
 Example bus mapping:
 
@@ -317,7 +362,10 @@ impl<F: FieldExt, const NUM_STEPS: usize> Config<F, NUM_STEPS> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, MemoryAddress, MemoryOp, ReadWrite, Step, Value};
+    use super::{
+        check_rw_consistency, Config, MemoryAddress, MemoryOp, ReadWrite, StateInconsistency, Step,
+        Value,
+    };
     use halo2::{
         circuit::layouter::SingleChipLayouter,
         dev::MockProver,
@@ -384,4 +432,56 @@ mod tests {
         let prover = MockProver::<pallas::Base>::run(11, &circuit, vec![]).unwrap();
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    #[test]
+    fn rw_consistency() {
+        let in_order = MemoryOp {
+            address: MemoryAddress(pallas::Base::zero()),
+            steps: vec![
+                Some(ReadWrite::Write(
+                    Step(12),
+                    Value(pallas::Base::from_u64(12)),
+                )),
+                None,
+                Some(ReadWrite::Read(Step(24), Value(pallas::Base::from_u64(12)))),
+            ],
+        };
+
+        let out_of_order = MemoryOp {
+            address: MemoryAddress(pallas::Base::one()),
+            steps: vec![
+                Some(ReadWrite::Write(
+                    Step(89),
+                    Value(pallas::Base::from_u64(32)),
+                )),
+                Some(ReadWrite::Read(Step(17), Value(pallas::Base::from_u64(32)))),
+            ],
+        };
+
+        let at_init_step = MemoryOp {
+            address: MemoryAddress(pallas::Base::from_u64(2)),
+            steps: vec![Some(ReadWrite::Write(
+                Step(0),
+                Value(pallas::Base::from_u64(7)),
+            ))],
+        };
+
+        assert_eq!(check_rw_consistency(&[in_order.clone()]), Ok(()));
+        assert_eq!(
+            check_rw_consistency(&[at_init_step]),
+            Err(StateInconsistency {
+                address: pallas::Base::from_u64(2),
+                prev_step: 0,
+                step: 0,
+            })
+        );
+        assert_eq!(
+            check_rw_consistency(&[in_order, out_of_order]),
+            Err(StateInconsistency {
+                address: pallas::Base::one(),
+                prev_step: 89,
+                step: 17,
+            })
+        );
+    }
 }
@@ -0,0 +1,28 @@
+//! Helpers shared by the crate's tests.
+
+/// Derives a deterministic rng seed from a test name, so that randomised
+/// tests are reproducible while still differing from one another.
+///
+/// Uses 64-bit FNV-1a, which (unlike `DefaultHasher`) is stable across Rust
+/// versions.
+pub(crate) fn seed_for(name: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    name.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+mod tests {
+    use super::seed_for;
+
+    #[test]
+    fn seed_for_name() {
+        assert_eq!(seed_for("memory_circuit"), seed_for("memory_circuit"));
+        assert_ne!(seed_for("memory_circuit"), seed_for("rw_consistency"));
+        // Known FNV-1a test vectors.
+        assert_eq!(seed_for(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(seed_for("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}